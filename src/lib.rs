@@ -1,32 +1,107 @@
-use hyper::body::{Body, Frame, SizeHint};
+use http_body_util::{BodyExt, Full};
+use hyper::body::{Body, Buf, Frame, SizeHint};
 use pin_project_lite::pin_project;
+use std::convert::Infallible;
+use std::future::Future;
 use std::pin::Pin;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 use tonic::body::Body as TonicBody;
 use tonic::transport::Channel;
+use tonic::Status;
+use tower_layer::Layer;
 use tower_service::Service;
 
+/// Which frames cause a [`FrameSignal`] to fire.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FrameKind {
+    /// Signal on the very first frame, including a trailers-only frame
+    /// that carries no application payload. This is the default, and
+    /// matches the behavior of earlier versions of this crate.
+    #[default]
+    AnyFrame,
+    /// Only signal once a non-empty DATA frame has been observed, so a
+    /// request body that yields only trailers or empty DATA frames doesn't
+    /// count as "contaminated".
+    DataOnly,
+}
+
 /// Resettable handle for indicating if a frame has been produced.
 #[derive(Clone, Debug, Default)]
-pub struct FrameSignal(Arc<AtomicBool>);
+pub struct FrameSignal {
+    signalled: Arc<AtomicBool>,
+    signal_on: FrameKind,
+}
 
 impl FrameSignal {
     fn signal(&self) {
-        self.0.store(true, Ordering::Release)
+        self.signalled.store(true, Ordering::Release)
+    }
+
+    /// Signals, if `frame` qualifies under this signal's [`FrameKind`].
+    fn observe<D: Buf>(&self, frame: &Frame<D>) {
+        match self.signal_on {
+            FrameKind::AnyFrame => self.signal(),
+            FrameKind::DataOnly => {
+                if frame.data_ref().is_some_and(|data| data.remaining() > 0) {
+                    self.signal();
+                }
+            }
+        }
     }
 
     pub fn new() -> Self {
-        Self(Arc::new(AtomicBool::new(false)))
+        Self::with_kind(FrameKind::AnyFrame)
+    }
+
+    /// Creates a signal that only fires for frames matching `signal_on`.
+    pub fn with_kind(signal_on: FrameKind) -> Self {
+        Self {
+            signalled: Arc::new(AtomicBool::new(false)),
+            signal_on,
+        }
     }
 
     pub fn is_signalled(&self) -> bool {
-        self.0.load(Ordering::Acquire)
+        self.signalled.load(Ordering::Acquire)
     }
 
     pub fn reset(&self) {
-        self.0.store(false, Ordering::Release)
+        self.signalled.store(false, Ordering::Release)
+    }
+}
+
+/// Running counts of frames and bytes actually emitted by a monitored
+/// body, so callers can tell *how much* was sent before a failure, not
+/// just whether anything was.
+#[derive(Debug, Default)]
+pub struct FrameStats {
+    frames: AtomicU64,
+    bytes: AtomicU64,
+}
+
+impl FrameStats {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Number of frames observed so far.
+    pub fn frames(&self) -> u64 {
+        self.frames.load(Ordering::Relaxed)
+    }
+
+    /// Total bytes of DATA frames observed so far.
+    pub fn bytes(&self) -> u64 {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    fn record<D: Buf>(&self, frame: &Frame<D>) {
+        self.frames.fetch_add(1, Ordering::Relaxed);
+        if let Some(data) = frame.data_ref() {
+            self.bytes.fetch_add(data.remaining() as u64, Ordering::Relaxed);
+        }
     }
 }
 
@@ -35,6 +110,7 @@ pin_project! {
         #[pin]
         inner: B,
         frame_signal: FrameSignal,
+        stats: Option<Arc<FrameStats>>,
     }
 }
 
@@ -53,7 +129,10 @@ where
         match this.inner.poll_frame(cx) {
             Poll::Ready(Some(res)) => match res {
                 Ok(frame) => {
-                    this.frame_signal.signal();
+                    this.frame_signal.observe(&frame);
+                    if let Some(stats) = this.stats {
+                        stats.record(&frame);
+                    }
                     Poll::Ready(Some(Ok(frame)))
                 }
                 Err(status) => Poll::Ready(Some(Err(status))),
@@ -83,6 +162,9 @@ where
 
     /// Signal indicating if request frame has been produced.
     frame_signal: FrameSignal,
+
+    /// Optional byte/frame accounting for the request body.
+    stats: Option<Arc<FrameStats>>,
 }
 
 impl<S: Clone> RequestFrameMonitor<S> {
@@ -90,8 +172,15 @@ impl<S: Clone> RequestFrameMonitor<S> {
         Self {
             inner,
             frame_signal: frame_signal.clone(),
+            stats: None,
         }
     }
+
+    /// Attaches a [`FrameStats`] handle to track bytes/frames observed.
+    pub fn with_stats(mut self, stats: Arc<FrameStats>) -> Self {
+        self.stats = Some(stats);
+        self
+    }
 }
 
 impl<S> Service<http::Request<TonicBody>> for RequestFrameMonitor<S>
@@ -111,6 +200,7 @@ where
         let body = TonicBody::new(RequestFrameMonitorBody {
             inner: body,
             frame_signal: self.frame_signal.clone(),
+            stats: self.stats.clone(),
         });
         // See <https://docs.rs/tower/latest/tower/trait.Service.html#be-careful-when-cloning-inner-services>
         let clone = self.inner.clone();
@@ -118,3 +208,765 @@ where
         inner.call(http::Request::from_parts(head, body))
     }
 }
+
+pin_project! {
+    struct ResponseFrameMonitorBody<B> {
+        #[pin]
+        inner: B,
+        first_data: FrameSignal,
+        trailers: FrameSignal,
+        stats: Option<Arc<FrameStats>>,
+    }
+}
+
+impl<B> Body for ResponseFrameMonitorBody<B>
+where
+    B: Body,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        match this.inner.poll_frame(cx) {
+            Poll::Ready(Some(res)) => match res {
+                Ok(frame) => {
+                    if frame.is_trailers() {
+                        this.trailers.signal();
+                    } else if frame.data_ref().is_some_and(|data| data.remaining() > 0) {
+                        this.first_data.signal();
+                    }
+                    if let Some(stats) = this.stats {
+                        stats.record(&frame);
+                    }
+                    Poll::Ready(Some(Ok(frame)))
+                }
+                Err(status) => Poll::Ready(Some(Err(status))),
+            },
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+pin_project! {
+    /// Future returned by [`ResponseFrameMonitor`], wrapping the eventual
+    /// response body in a [`ResponseFrameMonitorBody`].
+    pub struct ResponseFrameMonitorFuture<F> {
+        #[pin]
+        inner: F,
+        first_data: FrameSignal,
+        trailers: FrameSignal,
+        stats: Option<Arc<FrameStats>>,
+    }
+}
+
+impl<F, E> Future for ResponseFrameMonitorFuture<F>
+where
+    F: Future<Output = Result<http::Response<TonicBody>, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        match this.inner.poll(cx) {
+            Poll::Ready(Ok(response)) => {
+                let (parts, body) = response.into_parts();
+                let body = TonicBody::new(ResponseFrameMonitorBody {
+                    inner: body,
+                    first_data: this.first_data.clone(),
+                    trailers: this.trailers.clone(),
+                    stats: this.stats.clone(),
+                });
+                Poll::Ready(Ok(http::Response::from_parts(parts, body)))
+            }
+            Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Service for monitoring if and when a response's first DATA frame and
+/// its trailers frame were observed. Mirrors [`RequestFrameMonitor`], but
+/// on the response side: useful for TTFB metrics and for detecting that a
+/// server-streaming RPC has begun producing output, independent of when
+/// it finishes.
+#[derive(Clone, Debug)]
+pub struct ResponseFrameMonitor<S = Channel>
+where
+    S: Clone,
+{
+    /// Wrapped channel to monitor.
+    inner: S,
+
+    /// Signal indicating if the first response DATA frame has been observed.
+    first_data: FrameSignal,
+
+    /// Signal indicating if the response trailers frame has been observed.
+    trailers: FrameSignal,
+
+    /// Optional byte/frame accounting for the response body.
+    stats: Option<Arc<FrameStats>>,
+}
+
+impl<S: Clone> ResponseFrameMonitor<S> {
+    pub fn new(inner: S, first_data: FrameSignal, trailers: FrameSignal) -> Self {
+        Self {
+            inner,
+            first_data,
+            trailers,
+            stats: None,
+        }
+    }
+
+    /// Attaches a [`FrameStats`] handle to track bytes/frames observed.
+    pub fn with_stats(mut self, stats: Arc<FrameStats>) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+}
+
+impl<S> Service<http::Request<TonicBody>> for ResponseFrameMonitor<S>
+where
+    S: Service<http::Request<TonicBody>, Response = http::Response<TonicBody>> + Clone,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = ResponseFrameMonitorFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<TonicBody>) -> Self::Future {
+        // See <https://docs.rs/tower/latest/tower/trait.Service.html#be-careful-when-cloning-inner-services>
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        ResponseFrameMonitorFuture {
+            inner: inner.call(req),
+            first_data: self.first_data.clone(),
+            trailers: self.trailers.clone(),
+            stats: self.stats.clone(),
+        }
+    }
+}
+
+/// Backoff schedule used between [`SafeRetry`] attempts.
+#[derive(Clone, Debug)]
+pub enum Backoff {
+    /// Wait the same duration before every retry.
+    Fixed(Duration),
+    /// Double the wait after each retry, capped at `max`.
+    Exponential { base: Duration, max: Duration },
+}
+
+impl Backoff {
+    fn delay(&self, attempt: u32) -> Duration {
+        match self {
+            Backoff::Fixed(delay) => *delay,
+            Backoff::Exponential { base, max } => 2u32
+                .checked_pow(attempt)
+                .and_then(|factor| base.checked_mul(factor))
+                .unwrap_or(*max)
+                .min(*max),
+        }
+    }
+}
+
+/// Governs how many attempts [`SafeRetry`] makes, and how long it waits
+/// between them.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff: Backoff,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, backoff: Backoff) -> Self {
+        Self {
+            max_attempts,
+            backoff,
+        }
+    }
+}
+
+/// [`Layer`] that wraps a service in [`SafeRetry`].
+#[derive(Clone, Debug)]
+pub struct SafeRetryLayer {
+    policy: RetryPolicy,
+}
+
+impl SafeRetryLayer {
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl<S> Layer<S> for SafeRetryLayer {
+    type Service = SafeRetry<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        SafeRetry {
+            inner,
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+/// Error returned by [`SafeRetry`]: either the inner service's error, once
+/// retries were exhausted or unsafe, or a failure to buffer the request
+/// body up front so it could be replayed.
+#[derive(Debug)]
+pub enum SafeRetryError<E> {
+    /// The inner service failed and the call was not retried further.
+    Inner(E),
+    /// The request body could not be buffered for replay; the call was
+    /// never attempted.
+    Buffer(Status),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for SafeRetryError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SafeRetryError::Inner(err) => write!(f, "{err}"),
+            SafeRetryError::Buffer(status) => {
+                write!(f, "failed to buffer request body for replay: {status}")
+            }
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for SafeRetryError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SafeRetryError::Inner(err) => Some(err),
+            SafeRetryError::Buffer(status) => Some(status),
+        }
+    }
+}
+
+/// `tower::Layer`-compatible service that retries a unary gRPC call on
+/// transport failure, but only while [`FrameSignal::is_signalled`] remains
+/// `false` for the attempt that failed — i.e. no request byte reached the
+/// wire, so replay cannot duplicate a side effect.
+///
+/// The request body is buffered up front so a fresh [`TonicBody`] can be
+/// reconstructed for each attempt.
+#[derive(Clone, Debug)]
+pub struct SafeRetry<S> {
+    inner: S,
+    policy: RetryPolicy,
+}
+
+impl<S> SafeRetry<S> {
+    pub fn new(inner: S, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+impl<S> Service<http::Request<TonicBody>> for SafeRetry<S>
+where
+    S: Service<http::Request<TonicBody>, Response = http::Response<TonicBody>>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send,
+    S::Error: Send,
+{
+    type Response = S::Response;
+    type Error = SafeRetryError<S::Error>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(SafeRetryError::Inner)
+    }
+
+    fn call(&mut self, req: http::Request<TonicBody>) -> Self::Future {
+        // See <https://docs.rs/tower/latest/tower/trait.Service.html#be-careful-when-cloning-inner-services>
+        let clone = self.inner.clone();
+        let inner = std::mem::replace(&mut self.inner, clone);
+        let policy = self.policy.clone();
+
+        Box::pin(async move {
+            let (parts, body) = req.into_parts();
+            let method = parts.method;
+            let uri = parts.uri;
+            let version = parts.version;
+            let headers = parts.headers;
+            let extensions = parts.extensions;
+            // A body that can't be buffered can't be safely replayed, so
+            // fail the call instead of silently substituting an empty one.
+            let buffered = match body.collect().await {
+                Ok(collected) => collected.to_bytes(),
+                Err(status) => return Err(SafeRetryError::Buffer(status)),
+            };
+
+            // `DataOnly`: a trailers-only attempt (no payload ever left the
+            // client) is still safe to replay, so only a real DATA frame
+            // should mark this attempt as unsafe to retry.
+            let frame_signal = FrameSignal::with_kind(FrameKind::DataOnly);
+            let inner = inner;
+            let mut attempt: u32 = 0;
+            loop {
+                frame_signal.reset();
+
+                let mut builder = http::Request::builder()
+                    .method(method.clone())
+                    .uri(uri.clone())
+                    .version(version);
+                *builder.headers_mut().expect("builder has no error yet") = headers.clone();
+                *builder
+                    .extensions_mut()
+                    .expect("builder has no error yet") = extensions.clone();
+                let body = TonicBody::new(Full::new(buffered.clone()));
+                let request = builder
+                    .body(body)
+                    .expect("request parts were already valid on the first attempt");
+
+                let mut monitor = RequestFrameMonitor::new(inner.clone(), frame_signal.clone());
+                // A `poll_ready` failure happens before any request byte can
+                // have been sent, so it goes through the same give-up check
+                // below as a `call` failure rather than bailing out of the
+                // loop immediately.
+                let result = match std::future::poll_fn(|cx| monitor.poll_ready(cx)).await {
+                    Ok(()) => monitor.call(request).await,
+                    Err(err) => Err(err),
+                };
+                match result {
+                    Ok(response) => return Ok(response),
+                    Err(err) => {
+                        if frame_signal.is_signalled() || attempt + 1 >= policy.max_attempts {
+                            return Err(SafeRetryError::Inner(err));
+                        }
+                        // `attempt` is the number of retries already made, so
+                        // the first retry waits `delay(0)` as `Backoff` docs.
+                        tokio::time::sleep(policy.backoff.delay(attempt)).await;
+                        attempt += 1;
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Maps a recovered error into a [`Status`], consulting `frame_signal` so a
+/// frame that already reached the wire (and might be masking a side effect
+/// that already happened) is surfaced as-is rather than guessed as retryable.
+fn recovered_status(
+    err: Box<dyn std::error::Error + Send + Sync>,
+    frame_signal: &FrameSignal,
+) -> Status {
+    if frame_signal.is_signalled() {
+        Status::from_error(err)
+    } else {
+        Status::unavailable(err.to_string())
+    }
+}
+
+pin_project! {
+    /// Future returned by [`RecoverError`], turning an error into a
+    /// synthetic gRPC [`Status`] response.
+    ///
+    /// `ReadyError` covers the readiness-time failure case: `poll_ready`
+    /// already observed the error, so `call` must not touch the inner
+    /// service (it never signalled `Ready`) and instead returns a future
+    /// that resolves with the recovered status immediately.
+    #[project = RecoverErrorFutureProj]
+    pub enum RecoverErrorFuture<F> {
+        Inner {
+            #[pin]
+            inner: F,
+            frame_signal: FrameSignal,
+        },
+        ReadyError {
+            error: Option<Box<dyn std::error::Error + Send + Sync>>,
+            frame_signal: FrameSignal,
+        },
+    }
+}
+
+impl<F, E> Future for RecoverErrorFuture<F>
+where
+    F: Future<Output = Result<http::Response<TonicBody>, E>>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Output = Result<http::Response<TonicBody>, Infallible>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project() {
+            RecoverErrorFutureProj::Inner { inner, frame_signal } => match inner.poll(cx) {
+                Poll::Ready(Ok(response)) => Poll::Ready(Ok(response)),
+                Poll::Ready(Err(err)) => {
+                    let status = recovered_status(err.into(), frame_signal);
+                    Poll::Ready(Ok(status.into_http()))
+                }
+                Poll::Pending => Poll::Pending,
+            },
+            RecoverErrorFutureProj::ReadyError { error, frame_signal } => {
+                let err = error.take().expect("ReadyError future polled after completion");
+                let status = recovered_status(err, frame_signal);
+                Poll::Ready(Ok(status.into_http()))
+            }
+        }
+    }
+}
+
+/// Service that converts an inner service's/transport's errors into a
+/// synthetic `http::Response` carrying a gRPC [`Status`] in trailers,
+/// instead of letting an opaque tower error escape to the client.
+///
+/// Whether the status is retryable depends on [`FrameSignal::is_signalled`]:
+/// if no request frame was ever emitted the failure is mapped to
+/// `UNAVAILABLE`, which is safe to retry; once a frame has been signalled
+/// the error is surfaced as-is, since masking it could hide a side effect
+/// that already happened.
+#[derive(Debug)]
+pub struct RecoverError<S> {
+    inner: S,
+    frame_signal: FrameSignal,
+    /// A readiness-time error captured by `poll_ready`, waiting to be
+    /// turned into a `Status` by the next `call` instead of the inner
+    /// service, which never signalled `Ready`.
+    ready_error: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl<S> RecoverError<S> {
+    pub fn new(inner: S, frame_signal: FrameSignal) -> Self {
+        Self {
+            inner,
+            frame_signal,
+            ready_error: None,
+        }
+    }
+}
+
+impl<S: Clone> Clone for RecoverError<S> {
+    fn clone(&self) -> Self {
+        // A stashed readiness error belongs to this specific instance;
+        // a fresh clone starts without one.
+        Self {
+            inner: self.inner.clone(),
+            frame_signal: self.frame_signal.clone(),
+            ready_error: None,
+        }
+    }
+}
+
+impl<S, E> Service<http::Request<TonicBody>> for RecoverError<S>
+where
+    S: Service<http::Request<TonicBody>, Response = http::Response<TonicBody>, Error = E>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Response = http::Response<TonicBody>;
+    type Error = Infallible;
+    type Future = RecoverErrorFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Errors from the inner service are recovered into a `Status`
+        // response in `call`, so they must not be allowed to fail readiness.
+        // But the inner service is now genuinely not ready, so `call` must
+        // not forward to it; stash the error for `call` to recover instead.
+        match self.inner.poll_ready(cx) {
+            Poll::Ready(Ok(())) => Poll::Ready(Ok(())),
+            Poll::Ready(Err(err)) => {
+                self.ready_error = Some(err.into());
+                Poll::Ready(Ok(()))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn call(&mut self, req: http::Request<TonicBody>) -> Self::Future {
+        if let Some(error) = self.ready_error.take() {
+            return RecoverErrorFuture::ReadyError {
+                error: Some(error),
+                frame_signal: self.frame_signal.clone(),
+            };
+        }
+        RecoverErrorFuture::Inner {
+            inner: self.inner.call(req),
+            frame_signal: self.frame_signal.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+
+    #[test]
+    fn any_frame_signal_fires_on_trailers_only() {
+        let signal = FrameSignal::with_kind(FrameKind::AnyFrame);
+
+        signal.observe(&Frame::<Bytes>::trailers(http::HeaderMap::new()));
+
+        assert!(signal.is_signalled());
+    }
+
+    #[test]
+    fn data_only_signal_ignores_empty_and_trailers_frames() {
+        let signal = FrameSignal::with_kind(FrameKind::DataOnly);
+
+        signal.observe(&Frame::<Bytes>::trailers(http::HeaderMap::new()));
+        assert!(!signal.is_signalled());
+
+        signal.observe(&Frame::data(Bytes::new()));
+        assert!(!signal.is_signalled());
+
+        signal.observe(&Frame::data(Bytes::from_static(b"payload")));
+        assert!(signal.is_signalled());
+    }
+
+    #[test]
+    fn fixed_backoff_is_constant() {
+        let backoff = Backoff::Fixed(Duration::from_millis(50));
+        assert_eq!(backoff.delay(0), Duration::from_millis(50));
+        assert_eq!(backoff.delay(5), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn exponential_backoff_doubles_and_caps() {
+        let backoff = Backoff::Exponential {
+            base: Duration::from_millis(10),
+            max: Duration::from_millis(100),
+        };
+        assert_eq!(backoff.delay(0), Duration::from_millis(10));
+        assert_eq!(backoff.delay(1), Duration::from_millis(20));
+        assert_eq!(backoff.delay(2), Duration::from_millis(40));
+        assert_eq!(backoff.delay(10), Duration::from_millis(100));
+    }
+
+    /// Fails a fixed number of times, then succeeds, tracking how many
+    /// times it was actually called.
+    #[derive(Clone)]
+    struct FlakyService {
+        failures_left: Arc<AtomicU64>,
+        calls: Arc<AtomicU64>,
+    }
+
+    impl Service<http::Request<TonicBody>> for FlakyService {
+        type Response = http::Response<TonicBody>;
+        type Error = Status;
+        type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _req: http::Request<TonicBody>) -> Self::Future {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let still_flaky = self
+                .failures_left
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                    (n > 0).then(|| n - 1)
+                })
+                .is_ok();
+            if still_flaky {
+                std::future::ready(Err(Status::unavailable("connection reset")))
+            } else {
+                std::future::ready(Ok(http::Response::new(TonicBody::empty())))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_until_success_when_unsignalled() {
+        let service = FlakyService {
+            failures_left: Arc::new(AtomicU64::new(2)),
+            calls: Arc::new(AtomicU64::new(0)),
+        };
+        let calls = service.calls.clone();
+        let policy = RetryPolicy::new(5, Backoff::Fixed(Duration::from_millis(1)));
+        let mut retry = SafeRetry::new(service, policy);
+
+        let response = retry.call(http::Request::new(TonicBody::empty())).await;
+
+        assert!(response.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let service = FlakyService {
+            failures_left: Arc::new(AtomicU64::new(10)),
+            calls: Arc::new(AtomicU64::new(0)),
+        };
+        let calls = service.calls.clone();
+        let policy = RetryPolicy::new(3, Backoff::Fixed(Duration::from_millis(1)));
+        let mut retry = SafeRetry::new(service, policy);
+
+        let response = retry.call(http::Request::new(TonicBody::empty())).await;
+
+        assert!(matches!(response, Err(SafeRetryError::Inner(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    /// Fails `poll_ready` a fixed number of times, then reports ready,
+    /// tracking how many times `call` was actually reached.
+    #[derive(Clone)]
+    struct FlakyReadyService {
+        ready_failures_left: Arc<AtomicU64>,
+        calls: Arc<AtomicU64>,
+    }
+
+    impl Service<http::Request<TonicBody>> for FlakyReadyService {
+        type Response = http::Response<TonicBody>;
+        type Error = Status;
+        type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            let still_flaky = self
+                .ready_failures_left
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                    (n > 0).then(|| n - 1)
+                })
+                .is_ok();
+            if still_flaky {
+                Poll::Ready(Err(Status::unavailable("not ready")))
+            } else {
+                Poll::Ready(Ok(()))
+            }
+        }
+
+        fn call(&mut self, _req: http::Request<TonicBody>) -> Self::Future {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            std::future::ready(Ok(http::Response::new(TonicBody::empty())))
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_past_a_poll_ready_failure() {
+        let service = FlakyReadyService {
+            ready_failures_left: Arc::new(AtomicU64::new(1)),
+            calls: Arc::new(AtomicU64::new(0)),
+        };
+        let calls = service.calls.clone();
+        let policy = RetryPolicy::new(5, Backoff::Fixed(Duration::from_millis(1)));
+        let mut retry = SafeRetry::new(service, policy);
+
+        let response = retry.call(http::Request::new(TonicBody::empty())).await;
+
+        assert!(response.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    /// Always fails, but actually drains the request body first, so the
+    /// `RequestFrameMonitorBody` wrapping it observes a DATA frame and
+    /// signals, unlike `FlakyService`, which never polls `_req`'s body.
+    #[derive(Clone)]
+    struct DrainingFlakyService {
+        calls: Arc<AtomicU64>,
+    }
+
+    impl Service<http::Request<TonicBody>> for DrainingFlakyService {
+        type Response = http::Response<TonicBody>;
+        type Error = Status;
+        type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: http::Request<TonicBody>) -> Self::Future {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                let _ = req.into_body().collect().await;
+                Err(Status::unavailable("connection reset"))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn stops_retrying_once_request_frame_is_signalled() {
+        let service = DrainingFlakyService {
+            calls: Arc::new(AtomicU64::new(0)),
+        };
+        let calls = service.calls.clone();
+        let policy = RetryPolicy::new(5, Backoff::Fixed(Duration::from_millis(1)));
+        let mut retry = SafeRetry::new(service, policy);
+
+        let request = http::Request::new(TonicBody::new(Full::from("payload")));
+        let response = retry.call(request).await;
+
+        assert!(matches!(response, Err(SafeRetryError::Inner(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    /// A body that just yields a fixed sequence of frames, for driving the
+    /// monitor bodies directly without a real HTTP stack.
+    struct FrameQueueBody(std::collections::VecDeque<Frame<Bytes>>);
+
+    impl Body for FrameQueueBody {
+        type Data = Bytes;
+        type Error = Status;
+
+        fn poll_frame(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+            Poll::Ready(self.get_mut().0.pop_front().map(Ok))
+        }
+    }
+
+    #[tokio::test]
+    async fn response_monitor_distinguishes_first_data_from_trailers() {
+        let inner = FrameQueueBody(
+            [
+                Frame::data(Bytes::from_static(b"payload")),
+                Frame::trailers(http::HeaderMap::new()),
+            ]
+            .into(),
+        );
+        let first_data = FrameSignal::new();
+        let trailers = FrameSignal::new();
+        let mut body = ResponseFrameMonitorBody {
+            inner,
+            first_data: first_data.clone(),
+            trailers: trailers.clone(),
+            stats: None,
+        };
+
+        body.frame().await;
+        assert!(first_data.is_signalled());
+        assert!(!trailers.is_signalled());
+
+        body.frame().await;
+        assert!(trailers.is_signalled());
+    }
+
+    #[tokio::test]
+    async fn frame_stats_counts_data_bytes_and_excludes_trailers_from_bytes() {
+        let inner = FrameQueueBody(
+            [
+                Frame::data(Bytes::from_static(b"hello")),
+                Frame::data(Bytes::from_static(b"world!")),
+                Frame::trailers(http::HeaderMap::new()),
+            ]
+            .into(),
+        );
+        let stats = FrameStats::new();
+        let mut body = RequestFrameMonitorBody {
+            inner,
+            frame_signal: FrameSignal::new(),
+            stats: Some(stats.clone()),
+        };
+
+        while body.frame().await.is_some() {}
+
+        // 3 frames total, but the trailers frame carries no payload.
+        assert_eq!(stats.frames(), 3);
+        assert_eq!(stats.bytes(), "hello".len() as u64 + "world!".len() as u64);
+    }
+}